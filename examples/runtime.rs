@@ -0,0 +1,37 @@
+// main() notes that a vanilla fn main cannot be async, and that crates
+// provide solutions. This example is that solution: tokio's #[tokio::main]
+// attribute macro expands to a plain fn main that spins up a runtime and
+// block_on's the async body for you.
+//
+// This is a separate binary (cargo run --example runtime --features runtime)
+// kept behind the "runtime" feature so the core crate stays dependency-light:
+// the tokio dependency is declared `optional = true` in Cargo.toml, and this
+// example is marked `required-features = ["runtime"]` there.
+use std::time::Duration;
+
+async fn fetch_number(n: u64) -> u64 {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    n
+}
+
+#[tokio::main]
+async fn main() {
+    // spawn hands the task to tokio's work-stealing scheduler: it may run on
+    // any worker thread, concurrently with other spawned tasks. block_on, by
+    // contrast, runs a single future to completion on the current thread only.
+    let handle_a = tokio::spawn(fetch_number(1));
+    let handle_b = tokio::spawn(fetch_number(2));
+
+    let (a, b) = (
+        handle_a.await.expect("task panicked"),
+        handle_b.await.expect("task panicked"),
+    );
+    assert_eq!((1, 2), (a, b));
+
+    // join! (or tokio::join!) is the right tool when you just need to wait on
+    // a handful of futures together on the current task; prefer spawn when
+    // the futures are independent units of work that should run in parallel
+    // across threads, or should keep running even if you stop awaiting them.
+    let (c, d) = tokio::join!(fetch_number(3), fetch_number(4));
+    assert_eq!((3, 4), (c, d));
+}
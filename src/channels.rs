@@ -0,0 +1,59 @@
+// The standard pattern for bridging a blocking/CPU-bound std::thread into async code:
+// hand the result across with a futures channel instead of blocking the executor
+use futures::channel::{mpsc, oneshot};
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use std::thread;
+
+pub async fn run_demo() {
+    oneshot_demo().await;
+    mpsc_demo().await;
+}
+
+// oneshot: exactly one value crosses the thread boundary
+async fn oneshot_demo() {
+    let (tx, rx) = oneshot::channel();
+
+    thread::spawn(move || {
+        // Stand-in for an expensive computation that shouldn't block the executor
+        let result = 21 * 2;
+        tx.send(result).expect("receiver dropped");
+    });
+
+    let result = rx.await.expect("sender dropped without sending");
+    assert_eq!(42, result);
+}
+
+// mpsc: many producers feed a bounded channel, consumed on the async side as a Stream
+async fn mpsc_demo() {
+    let (tx, rx) = mpsc::channel(4);
+
+    for i in 0..3 {
+        let mut tx = tx.clone();
+        thread::spawn(move || {
+            // futures::executor::block_on lets a plain thread drive the async send
+            futures::executor::block_on(tx.send(i)).expect("receiver dropped");
+        });
+    }
+    drop(tx);
+
+    let mut results: Vec<i32> = rx.collect().await;
+    results.sort_unstable();
+    assert_eq!(vec![0, 1, 2], results);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_oneshot_channel_delivers_value() {
+        block_on(oneshot_demo());
+    }
+
+    #[test]
+    fn test_mpsc_channel_collects_all_producers() {
+        block_on(mpsc_demo());
+    }
+}
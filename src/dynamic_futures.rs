@@ -0,0 +1,48 @@
+// Each async fn has its own anonymous, unnameable return type, so you can't
+// put the results of different async fns into a plain Vec.
+// Boxing and pinning them behind `dyn Future` erases that type difference.
+use futures::future::{self, BoxFuture, FutureExt};
+use std::future::Future;
+use std::pin::Pin;
+
+async fn some(i: i32) -> Option<i32> {
+    Some(i + 1)
+}
+
+async fn none(_i: i32) -> Option<i32> {
+    None
+}
+
+pub async fn run_demo() {
+    // Built by hand: Box::pin erases each async fn's distinct type down to dyn Future
+    let futures_by_hand: Vec<Pin<Box<dyn Future<Output = Option<i32>>>>> =
+        vec![Box::pin(some(1)), Box::pin(none(2)), Box::pin(some(3))];
+
+    let mut results_by_hand = Vec::new();
+    for future in futures_by_hand {
+        results_by_hand.push(future.await);
+    }
+    assert_eq!(vec![Some(2), None, Some(4)], results_by_hand);
+
+    // BoxFuture<'a, T> is just a type alias for Pin<Box<dyn Future<Output = T> + Send + 'a>>
+    // FutureExt::boxed is the ergonomic shortcut for Box::pin
+    let boxed_futures: Vec<BoxFuture<Option<i32>>> =
+        vec![some(10).boxed(), none(20).boxed(), some(30).boxed()];
+
+    // join_all runs every future in the Vec concurrently and waits for them all
+    let joined_results = future::join_all(boxed_futures).await;
+    assert_eq!(vec![Some(11), None, Some(31)], joined_results);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_boxed_futures_join_all() {
+        let boxed_futures: Vec<BoxFuture<Option<i32>>> = vec![some(1).boxed(), none(2).boxed()];
+        let results = block_on(future::join_all(boxed_futures));
+        assert_eq!(vec![Some(2), None], results);
+    }
+}
@@ -0,0 +1,116 @@
+// A minimal single-threaded executor, built from scratch
+// This shows what block_on actually does under the hood:
+// park a Future, give it a Waker, and poll it until it's Ready
+use futures::task::{waker_ref, ArcWake};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+// A Task is a Future plus a way to re-enqueue itself when woken
+// Mutex::lock() already hands out an exclusive &mut, so a single layer of
+// interior mutability is enough to take the future out, poll it, and put it back
+type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+struct Task {
+    future: Mutex<Option<BoxedFuture>>,
+    task_sender: SyncSender<Arc<Task>>,
+}
+
+// ArcWake turns an Arc<Task> into a Waker
+// wake_by_ref just pushes the task back onto the ready-queue
+impl ArcWake for Task {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self
+            .task_sender
+            .send(arc_self.clone())
+            .expect("executor dropped");
+    }
+}
+
+// The Spawner hands out Tasks, the Executor drains and polls them
+pub struct Spawner {
+    task_sender: SyncSender<Arc<Task>>,
+}
+
+impl Spawner {
+    pub fn spawn(&self, future: impl Future<Output = ()> + 'static + Send) {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            task_sender: self.task_sender.clone(),
+        });
+        self.task_sender.send(task).expect("executor dropped");
+    }
+}
+
+pub struct Executor {
+    ready_queue: Receiver<Arc<Task>>,
+}
+
+impl Executor {
+    // Bound the channel so a runaway spawn loop can't grow memory without limit
+    pub fn new() -> (Executor, Spawner) {
+        const MAX_QUEUED_TASKS: usize = 10_000;
+        let (task_sender, ready_queue) = sync_channel(MAX_QUEUED_TASKS);
+        (Executor { ready_queue }, Spawner { task_sender })
+    }
+
+    pub fn run(&self) {
+        while let Ok(task) = self.ready_queue.recv() {
+            let mut future = match task.future.lock().unwrap().take() {
+                Some(future) => future,
+                None => continue,
+            };
+            let waker = waker_ref(&task);
+            let context = &mut Context::from_waker(&waker);
+            if future.as_mut().poll(context) == Poll::Pending {
+                // Not done yet, put it back so the next wake can resume it
+                *task.future.lock().unwrap() = Some(future);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::increment;
+
+    // A Future that is Pending on its first poll and Ready on the second,
+    // so running it exercises the wake path instead of completing in one shot
+    struct PendingOnce {
+        polled_once: bool,
+    }
+
+    impl Future for PendingOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.polled_once {
+                Poll::Ready(())
+            } else {
+                self.polled_once = true;
+                // Wake ourselves immediately instead of waiting on an external event
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_drives_tasks_to_completion() {
+        let (executor, spawner) = Executor::new();
+
+        spawner.spawn(async {
+            let result = increment(4).await;
+            assert_eq!(5, result);
+        });
+        spawner.spawn(async {
+            PendingOnce { polled_once: false }.await;
+        });
+
+        drop(spawner);
+        executor.run();
+    }
+}
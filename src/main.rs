@@ -1,6 +1,12 @@
 use futures::{executor::block_on, future::*, *};
 use std::future::Future;
 
+mod channels;
+mod dynamic_futures;
+mod executor;
+mod streams;
+mod timer;
+
 // The keyword “async” provides syntactic sugar
 // An async fn returns a Future
 // https://doc.rust-lang.org/std/future/trait.Future.html
@@ -80,6 +86,30 @@ fn main() {
         ];
         dbg!(result7);
     });
+
+    // block_on isn't the only way to drive a Future to completion
+    // See executor.rs for a minimal single-threaded executor built from scratch,
+    // with its own task queue and Waker
+    let (custom_executor, spawner) = executor::Executor::new();
+    spawner.spawn(async {
+        let result8 = increment(4).await;
+        assert_eq!(5, result8);
+    });
+    drop(spawner);
+    custom_executor.run();
+
+    // See timer.rs for a Future implemented by hand, showing the poll/Waker
+    // protocol that async fn normally hides from you
+    block_on(timer::TimerFuture::new(std::time::Duration::from_millis(10)));
+
+    // Stream is the async analogue of Iterator, see streams.rs
+    block_on(streams::run_demo());
+
+    // Storing async fns of different types in a Vec needs Pin<Box<dyn Future>>, see dynamic_futures.rs
+    block_on(dynamic_futures::run_demo());
+
+    // Bridging a blocking std::thread into async code with oneshot/mpsc channels, see channels.rs
+    block_on(channels::run_demo());
 }
 
 #[cfg(test)]
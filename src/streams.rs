@@ -0,0 +1,82 @@
+// Stream is the async analogue of Iterator: a sequence of values produced over time
+// https://docs.rs/futures/0.3.13/futures/stream/trait.Stream.html
+use futures::stream::{self, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// A counter that yields 0, 1, 2 and then ends, implemented by hand instead
+// of going through stream::iter
+struct Counter {
+    count: u8,
+}
+
+impl Stream for Counter {
+    type Item = u8;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.count < 3 {
+            self.count += 1;
+            Poll::Ready(Some(self.count - 1))
+        } else {
+            Poll::Ready(None)
+        }
+    }
+}
+
+pub async fn run_demo() {
+    // Built with stream::iter, consumed one item at a time with .next().await
+    let mut basic_stream = stream::iter(1..=3);
+    let mut collected = Vec::new();
+    while let Some(n) = basic_stream.next().await {
+        collected.push(n);
+    }
+    assert_eq!(vec![1, 2, 3], collected);
+
+    // Combinators mirror the Iterator ones: map, filter, fold
+    let doubled: Vec<i32> = stream::iter(1..=5).map(|n| n * 2).collect().await;
+    assert_eq!(vec![2, 4, 6, 8, 10], doubled);
+
+    let evens: Vec<i32> = stream::iter(1..=5)
+        .filter(|n| futures::future::ready(n % 2 == 0))
+        .collect()
+        .await;
+    assert_eq!(vec![2, 4], evens);
+
+    let sum = stream::iter(1..=5).fold(0, |acc, n| async move { acc + n }).await;
+    assert_eq!(15, sum);
+
+    // buffer_unordered runs up to N futures concurrently and yields results as they complete
+    let results: Vec<i32> = stream::iter(1..=5)
+        .map(|n| async move { n * n })
+        .buffer_unordered(2)
+        .collect()
+        .await;
+    assert_eq!(55, results.iter().sum::<i32>());
+
+    // The hand-rolled Counter stream works with the same combinators
+    let counted: Vec<u8> = Counter { count: 0 }.collect().await;
+    assert_eq!(vec![0, 1, 2], counted);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_counter_stream_yields_then_ends() {
+        let counted: Vec<u8> = block_on(Counter { count: 0 }.collect());
+        assert_eq!(vec![0, 1, 2], counted);
+    }
+
+    #[test]
+    fn test_buffer_unordered_runs_all_futures() {
+        let results: Vec<i32> = block_on(
+            stream::iter(1..=5)
+                .map(|n| async move { n * n })
+                .buffer_unordered(2)
+                .collect(),
+        );
+        assert_eq!(55, results.iter().sum::<i32>());
+    }
+}
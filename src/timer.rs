@@ -0,0 +1,71 @@
+// A Future implemented by hand, no async fn sugar
+// Demonstrates the wakeup protocol every executor relies on:
+// poll stashes a Waker, and whoever finishes the work later calls wake() on it
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+struct SharedState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+pub struct TimerFuture {
+    shared_state: Arc<Mutex<SharedState>>,
+}
+
+impl TimerFuture {
+    // Spawns a thread that sleeps for `duration`, then marks the timer complete and wakes the task
+    pub fn new(duration: Duration) -> Self {
+        let shared_state = Arc::new(Mutex::new(SharedState {
+            completed: false,
+            waker: None,
+        }));
+
+        let thread_shared_state = shared_state.clone();
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut shared_state = thread_shared_state.lock().unwrap();
+            shared_state.completed = true;
+            if let Some(waker) = shared_state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        TimerFuture { shared_state }
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared_state = self.shared_state.lock().unwrap();
+        if shared_state.completed {
+            Poll::Ready(())
+        } else {
+            // The task may have moved to a different executor (or waker) between polls,
+            // so the waker must be refreshed on every poll rather than stored once
+            shared_state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::time::Instant;
+
+    #[test]
+    fn test_timer_future_completes_after_delay() {
+        let duration = Duration::from_millis(50);
+        let start = Instant::now();
+        block_on(TimerFuture::new(duration));
+        assert!(start.elapsed() >= duration);
+    }
+}